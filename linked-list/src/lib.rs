@@ -1,116 +1,715 @@
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ptr::NonNull;
 
-struct Node {
-    data: String,
-    next: Option<Box<Node>>,
+mod persistent;
+pub use persistent::PersistentList;
+
+struct Node<T> {
+    next: Option<NonNull<Node<T>>>,
+    prev: Option<NonNull<Node<T>>>,
+    data: T,
+}
+
+impl<T> Node<T> {
+    fn new(data: T) -> Self {
+        Node {
+            next: None,
+            prev: None,
+            data,
+        }
+    }
+
+    fn into_data(self: Box<Self>) -> T {
+        self.data
+    }
 }
 
-pub struct LinkedList {
-    head: Option<Box<Node>>,
-    size: usize,
+pub struct LinkedList<T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<Box<Node<T>>>,
 }
 
-impl LinkedList {
+impl<T> LinkedList<T> {
     pub fn new() -> Self {
         LinkedList {
             head: None,
-            size: 0,
+            tail: None,
+            len: 0,
+            marker: PhantomData,
         }
     }
 
-    pub fn push(&mut self, data: String) {
-        let new_node = Box::new(Node {
-            data,
-            next: self.head.take(),
-        });
-        self.head = Some(new_node);
-        self.size += 1;
+    fn push_front_node(&mut self, mut node: Box<Node<T>>) {
+        unsafe {
+            node.next = self.head;
+            node.prev = None;
+            let node = Some(NonNull::from(Box::leak(node)));
+
+            match self.head {
+                None => self.tail = node,
+                Some(head) => (*head.as_ptr()).prev = node,
+            }
+
+            self.head = node;
+            self.len += 1;
+        }
+    }
+
+    fn pop_front_node(&mut self) -> Option<Box<Node<T>>> {
+        self.head.map(|node| unsafe {
+            let node = Box::from_raw(node.as_ptr());
+            self.head = node.next;
+
+            match self.head {
+                None => self.tail = None,
+                Some(head) => (*head.as_ptr()).prev = None,
+            }
+
+            self.len -= 1;
+            node
+        })
+    }
+
+    fn push_back_node(&mut self, mut node: Box<Node<T>>) {
+        unsafe {
+            node.next = None;
+            node.prev = self.tail;
+            let node = Some(NonNull::from(Box::leak(node)));
+
+            match self.tail {
+                None => self.head = node,
+                Some(tail) => (*tail.as_ptr()).next = node,
+            }
+
+            self.tail = node;
+            self.len += 1;
+        }
+    }
+
+    fn pop_back_node(&mut self) -> Option<Box<Node<T>>> {
+        self.tail.map(|node| unsafe {
+            let node = Box::from_raw(node.as_ptr());
+            self.tail = node.prev;
+
+            match self.tail {
+                None => self.head = None,
+                Some(tail) => (*tail.as_ptr()).next = None,
+            }
+
+            self.len -= 1;
+            node
+        })
+    }
+
+    /// Pushes `data` onto the front of the list in O(1).
+    pub fn push_front(&mut self, data: T) {
+        self.push_front_node(Box::new(Node::new(data)));
+    }
+
+    /// Removes and returns the front element in O(1).
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.pop_front_node().map(Node::into_data)
+    }
+
+    /// Pushes `data` onto the back of the list in O(1).
+    pub fn push_back(&mut self, data: T) {
+        self.push_back_node(Box::new(Node::new(data)));
+    }
+
+    /// Removes and returns the back element in O(1).
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.pop_back_node().map(Node::into_data)
     }
 
-    pub fn pop(&mut self) -> Option<String> {
-        let node = self.head.take()?;
-        self.head = node.next;
-        self.size -= 1;
-        Some(node.data)
+    pub fn len(&self) -> usize {
+        self.len
     }
 
-    pub fn len(&mut self) -> usize {
-        self.size
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
     }
 
-    pub fn is_empty(&mut self) -> bool {
-        self.head.is_none()
+    pub fn front(&self) -> Option<&T> {
+        unsafe { self.head.as_ref().map(|node| &node.as_ref().data) }
     }
 
-    pub fn peek(&self) -> Option<&String> {
-        self.head.as_ref().map(|node| &node.data)
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        unsafe { self.head.as_mut().map(|node| &mut node.as_mut().data) }
     }
 
-    pub fn iter(&self) -> Iter {
-        Iter { next: self.head.as_deref() }
+    pub fn back(&self) -> Option<&T> {
+        unsafe { self.tail.as_ref().map(|node| &node.as_ref().data) }
     }
 
-    pub fn iter_mut(&mut self) -> IterMut {
-        IterMut { next: self.head.as_deref_mut() }
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        unsafe { self.tail.as_mut().map(|node| &mut node.as_mut().data) }
     }
 
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a cursor positioned on the front element, for O(1)
+    /// insertion/removal at arbitrary positions reached by walking the
+    /// cursor. The cursor sits "between" nodes, so on an empty list it
+    /// starts at the ghost (non-element) position.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head,
+            index: 0,
+            list: self,
+        }
+    }
+
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let index = self.len.saturating_sub(1);
+        CursorMut {
+            current: self.tail,
+            index,
+            list: self,
+        }
+    }
+
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor {
+            current: self.head,
+            index: 0,
+            list: self,
+        }
+    }
+
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        let index = self.len.saturating_sub(1);
+        Cursor {
+            current: self.tail,
+            index,
+            list: self,
+        }
+    }
+
+    /// Moves all of `other`'s nodes onto the end of `self` in O(1) by
+    /// splicing the tail pointer, leaving `other` empty.
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        match self.tail {
+            None => std::mem::swap(self, other),
+            Some(tail) => {
+                if let Some(other_head) = other.head.take() {
+                    unsafe {
+                        (*tail.as_ptr()).next = Some(other_head);
+                        (*other_head.as_ptr()).prev = Some(tail);
+                    }
+                    self.tail = other.tail.take();
+                    self.len += other.len;
+                    other.len = 0;
+                }
+            }
+        }
+    }
+
+    /// Splits the list at index `at`, returning everything from `at` onward
+    /// as a new list and leaving `self` with the first `at` elements.
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        assert!(at <= self.len, "Cannot split off at a nonexistent index");
+        if at == 0 {
+            return std::mem::replace(self, LinkedList::new());
+        }
+        let mut cursor = self.cursor_front_mut();
+        for _ in 0..at - 1 {
+            cursor.move_next();
+        }
+        cursor.split_after()
+    }
+
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == x)
+    }
+
+    /// Removes and returns the element at `index`, or `None` if out of
+    /// bounds. Walks the list with a cursor, so this is O(index).
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        let mut cursor = self.cursor_front_mut();
+        for _ in 0..index {
+            cursor.move_next();
+        }
+        cursor.remove_current()
+    }
 }
 
 // Implement the drop trait so that nodes are deallocated iteratively
 // instead of recursively (the default). Recursive cleanup could cause
-// a stack overflow.
-impl Drop for LinkedList {
+// a stack overflow. Reusing `pop_front_node` keeps the walk-and-free loop
+// in a single place instead of duplicating the unsafe bookkeeping here.
+impl<T> Drop for LinkedList<T> {
     fn drop(&mut self) {
-        let mut current_node = self.head.take();
-        while let Some(mut boxed_node) = current_node {  // boxing to heap allocate
-            current_node = boxed_node.next.take();  // taking ownership
-        }
+        while self.pop_front_node().is_some() {}
     }
 }
 
-impl fmt::Display for LinkedList {
+impl<T> fmt::Display for LinkedList<T>
+where
+    T: fmt::Display,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "[")?;
-        let mut current = self.head.as_ref();
-        while let Some(node) = current {
-            write!(f, "{}", node.data)?;
-            if node.next.is_some() {
-                write!(f, ", ")?;
+        let mut iter = self.iter();
+        if let Some(first) = iter.next() {
+            write!(f, "{}", first)?;
+            for item in iter {
+                write!(f, ", {}", item)?;
             }
-            current = node.next.as_ref();
         }
         write!(f, "]")
     }
 }
 
-// Iterator for immutable borrowing
-pub struct Iter<'a> {
-    next: Option<&'a Node>,
+// Iterator for immutable borrowing. `tail` and `len` are carried alongside
+// `head` so `next_back` can pop from the back without re-walking the list
+// to find where it currently ends.
+pub struct Iter<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a Node<T>>,
 }
 
-impl<'a> Iterator for Iter<'a> {
-    type Item = &'a String;
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
     fn next(&mut self) -> Option<Self::Item> {
-        self.next.map(|node| {
-            self.next = node.next.as_deref();
-            &node.data
-        })
+        if self.len == 0 {
+            None
+        } else {
+            self.head.map(|node| unsafe {
+                let node = &*node.as_ptr();
+                self.len -= 1;
+                self.head = node.next;
+                &node.data
+            })
+        }
     }
 }
 
-// Iterator for mutable borrowing
-pub struct IterMut<'a> {
-    next: Option<&'a mut Node>
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            None
+        } else {
+            self.tail.map(|node| unsafe {
+                let node = &*node.as_ptr();
+                self.len -= 1;
+                self.tail = node.prev;
+                &node.data
+            })
+        }
+    }
 }
 
-impl<'a> Iterator for IterMut<'a> {
-    type Item = &'a mut String;
+// Iterator for mutable borrowing. See `Iter` for why `tail`/`len` are kept.
+pub struct IterMut<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.next.take().map(|node| {
-            self.next = node.next.as_deref_mut();
-            &mut node.data
-        })
+        if self.len == 0 {
+            None
+        } else {
+            self.head.map(|node| unsafe {
+                let node = &mut *node.as_ptr();
+                self.len -= 1;
+                self.head = node.next;
+                &mut node.data
+            })
+        }
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            None
+        } else {
+            self.tail.map(|node| unsafe {
+                let node = &mut *node.as_ptr();
+                self.len -= 1;
+                self.tail = node.prev;
+                &mut node.data
+            })
+        }
+    }
+}
+
+/// A consuming iterator, produced by `LinkedList::into_iter` (and thus by
+/// `for x in list`), that drains the list from the front.
+pub struct IntoIter<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = LinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+impl<T: Hash> Hash for LinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for item in self {
+            item.hash(state);
+        }
+    }
+}
+
+/// A read-only cursor over a `LinkedList`. Sits "between" elements: an
+/// exhausted cursor is at the ghost position (`current` is `None`), one
+/// step past the back and one step before the front.
+pub struct Cursor<'a, T> {
+    current: Option<NonNull<Node<T>>>,
+    index: usize,
+    list: &'a LinkedList<T>,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    pub fn current(&self) -> Option<&T> {
+        unsafe { self.current.as_ref().map(|node| &node.as_ref().data) }
+    }
+
+    pub fn peek_next(&self) -> Option<&T> {
+        let next = match self.current {
+            Some(node) => unsafe { node.as_ref().next },
+            None => self.list.head,
+        };
+        unsafe { next.as_ref().map(|node| &node.as_ref().data) }
+    }
+
+    pub fn peek_prev(&self) -> Option<&T> {
+        let prev = match self.current {
+            Some(node) => unsafe { node.as_ref().prev },
+            None => self.list.tail,
+        };
+        unsafe { prev.as_ref().map(|node| &node.as_ref().data) }
+    }
+
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(node) => unsafe {
+                self.current = node.as_ref().next;
+                self.index += 1;
+            },
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(node) => unsafe {
+                self.current = node.as_ref().prev;
+                self.index = self.index.checked_sub(1).unwrap_or(self.list.len);
+            },
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+}
+
+/// A cursor that can mutate the `LinkedList` it walks: splice nodes in or
+/// out next to `current` in O(1) without disturbing the rest of the list.
+pub struct CursorMut<'a, T> {
+    current: Option<NonNull<Node<T>>>,
+    index: usize,
+    list: &'a mut LinkedList<T>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        unsafe { self.current.as_mut().map(|node| &mut node.as_mut().data) }
+    }
+
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = match self.current {
+            Some(node) => unsafe { node.as_ref().next },
+            None => self.list.head,
+        };
+        unsafe { next.map(|mut node| &mut node.as_mut().data) }
+    }
+
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let prev = match self.current {
+            Some(node) => unsafe { node.as_ref().prev },
+            None => self.list.tail,
+        };
+        unsafe { prev.map(|mut node| &mut node.as_mut().data) }
+    }
+
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(node) => unsafe {
+                self.current = node.as_ref().next;
+                self.index += 1;
+            },
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(node) => unsafe {
+                self.current = node.as_ref().prev;
+                self.index = self.index.checked_sub(1).unwrap_or(self.list.len);
+            },
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Inserts `elem` immediately after the cursor's position. At the ghost
+    /// position (ahead of the back / behind the front) this is equivalent
+    /// to `push_front`.
+    pub fn insert_after(&mut self, elem: T) {
+        match self.current {
+            None => self.list.push_front(elem),
+            Some(cur) => unsafe {
+                let mut new_node = Box::new(Node::new(elem));
+                new_node.prev = Some(cur);
+                new_node.next = (*cur.as_ptr()).next;
+                let new_node = NonNull::from(Box::leak(new_node));
+
+                match (*cur.as_ptr()).next {
+                    Some(next) => (*next.as_ptr()).prev = Some(new_node),
+                    None => self.list.tail = Some(new_node),
+                }
+                (*cur.as_ptr()).next = Some(new_node);
+                self.list.len += 1;
+            },
+        }
+    }
+
+    /// Inserts `elem` immediately before the cursor's position. At the
+    /// ghost position this is equivalent to `push_back`.
+    pub fn insert_before(&mut self, elem: T) {
+        match self.current {
+            None => self.list.push_back(elem),
+            Some(cur) => unsafe {
+                let mut new_node = Box::new(Node::new(elem));
+                new_node.next = Some(cur);
+                new_node.prev = (*cur.as_ptr()).prev;
+                let new_node = NonNull::from(Box::leak(new_node));
+
+                match (*cur.as_ptr()).prev {
+                    Some(prev) => (*prev.as_ptr()).next = Some(new_node),
+                    None => self.list.head = Some(new_node),
+                }
+                (*cur.as_ptr()).prev = Some(new_node);
+                self.list.len += 1;
+                self.index += 1;
+            },
+        }
+    }
+
+    /// Removes and returns the element under the cursor in O(1), leaving
+    /// the cursor on the element that followed it (or the ghost position,
+    /// if the removed element was the last one).
+    pub fn remove_current(&mut self) -> Option<T> {
+        let cur = self.current?;
+        unsafe {
+            let next = (*cur.as_ptr()).next;
+            let prev = (*cur.as_ptr()).prev;
+
+            match prev {
+                Some(prev) => (*prev.as_ptr()).next = next,
+                None => self.list.head = next,
+            }
+            match next {
+                Some(next) => (*next.as_ptr()).prev = prev,
+                None => self.list.tail = prev,
+            }
+
+            self.list.len -= 1;
+            self.current = next;
+            if next.is_none() {
+                self.index = self.list.len;
+            }
+
+            Some(Box::from_raw(cur.as_ptr()).into_data())
+        }
+    }
+
+    /// Splits the list after the cursor, returning everything past the
+    /// current element as a new list. At the ghost position the whole list
+    /// lies "after" it, so the original list is emptied and handed back.
+    pub fn split_after(&mut self) -> LinkedList<T> {
+        match self.current {
+            None => std::mem::replace(self.list, LinkedList::new()),
+            Some(cur) => unsafe {
+                let split_off_len = self.list.len - self.index - 1;
+                let new_head = (*cur.as_ptr()).next;
+                (*cur.as_ptr()).next = None;
+
+                let new_tail = match new_head {
+                    Some(_) => self.list.tail,
+                    None => None,
+                };
+                self.list.tail = Some(cur);
+                self.list.len = self.index + 1;
+
+                if let Some(head) = new_head {
+                    (*head.as_ptr()).prev = None;
+                }
+
+                LinkedList {
+                    head: new_head,
+                    tail: new_tail,
+                    len: split_off_len,
+                    marker: PhantomData,
+                }
+            },
+        }
+    }
+
+    /// Splits the list before the cursor, returning everything before the
+    /// current element as a new list. At the ghost position the whole list
+    /// lies "before" it, so the original list is emptied and handed back.
+    pub fn split_before(&mut self) -> LinkedList<T> {
+        match self.current {
+            None => std::mem::replace(self.list, LinkedList::new()),
+            Some(cur) => unsafe {
+                let split_off_len = self.index;
+                let old_head = self.list.head;
+                let new_tail = (*cur.as_ptr()).prev;
+                (*cur.as_ptr()).prev = None;
+
+                self.list.head = Some(cur);
+                self.list.len -= split_off_len;
+                self.index = 0;
+
+                let new_head = match new_tail {
+                    Some(_) => old_head,
+                    None => None,
+                };
+                if let Some(tail) = new_tail {
+                    (*tail.as_ptr()).next = None;
+                }
+
+                LinkedList {
+                    head: new_head,
+                    tail: new_tail,
+                    len: split_off_len,
+                    marker: PhantomData,
+                }
+            },
+        }
     }
 }
 
@@ -119,29 +718,56 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_push_pop() {
+    fn test_push_pop_front() {
         let mut list = LinkedList::new();
-        list.push("three".to_string());
-        list.push("two".to_string());
-        list.push("one".to_string());
+        list.push_front("three".to_string());
+        list.push_front("two".to_string());
+        list.push_front("one".to_string());
 
-        assert_eq!(list.pop(), Some("one".to_string()));
-        assert_eq!(list.pop(), Some("two".to_string()));
-        assert_eq!(list.pop(), Some("three".to_string()));
-        assert_eq!(list.pop(), None);
+        assert_eq!(list.pop_front(), Some("one".to_string()));
+        assert_eq!(list.pop_front(), Some("two".to_string()));
+        assert_eq!(list.pop_front(), Some("three".to_string()));
+        assert_eq!(list.pop_front(), None);
+    }
+
+    #[test]
+    fn test_push_pop_back() {
+        let mut list = LinkedList::new();
+        list.push_back("one".to_string());
+        list.push_back("two".to_string());
+        list.push_back("three".to_string());
+
+        assert_eq!(list.pop_back(), Some("three".to_string()));
+        assert_eq!(list.pop_back(), Some("two".to_string()));
+        assert_eq!(list.pop_back(), Some("one".to_string()));
+        assert_eq!(list.pop_back(), None);
+    }
+
+    #[test]
+    fn test_mixed_ends() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_front(0);
+        list.push_back(2);
+
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
     }
 
     #[test]
     fn test_len() {
         let mut list = LinkedList::new();
         assert_eq!(list.len(), 0);
-        list.push("hello".to_string());
+        list.push_back("hello".to_string());
         assert_eq!(list.len(), 1);
-        list.push("world".to_string());
+        list.push_front("world".to_string());
         assert_eq!(list.len(), 2);
-        list.pop();
+        list.pop_back();
         assert_eq!(list.len(), 1);
-        list.pop();
+        list.pop_front();
         assert_eq!(list.len(), 0);
     }
 
@@ -149,40 +775,50 @@ mod tests {
     fn test_is_empty() {
         let mut list = LinkedList::new();
         assert!(list.is_empty());
-        list.push("test".to_string());
+        list.push_back("test".to_string());
         assert!(!list.is_empty());
-        list.pop();
+        list.pop_front();
         assert!(list.is_empty());
     }
 
     #[test]
-    fn test_peek() {
+    fn test_front_back() {
         let mut list = LinkedList::new();
-        assert_eq!(list.peek(), None);
-        list.push("first".to_string());
-        assert_eq!(list.peek(), Some(&"first".to_string()));
-        list.push("second".to_string());
-        assert_eq!(list.peek(), Some(&"second".to_string()));
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+
+        list.push_back("first".to_string());
+        assert_eq!(list.front(), Some(&"first".to_string()));
+        assert_eq!(list.back(), Some(&"first".to_string()));
+
+        list.push_back("second".to_string());
+        assert_eq!(list.front(), Some(&"first".to_string()));
+        assert_eq!(list.back(), Some(&"second".to_string()));
+
+        *list.front_mut().unwrap() = "FIRST".to_string();
+        *list.back_mut().unwrap() = "SECOND".to_string();
+        assert_eq!(list.front(), Some(&"FIRST".to_string()));
+        assert_eq!(list.back(), Some(&"SECOND".to_string()));
     }
 
     #[test]
     fn test_display() {
         let mut list = LinkedList::new();
         assert_eq!(format!("{}", list), "[]");
-        list.push("a".to_string());
+        list.push_front("a".to_string());
         assert_eq!(format!("{}", list), "[a]");
-        list.push("b".to_string());
+        list.push_front("b".to_string());
         assert_eq!(format!("{}", list), "[b, a]");
-        list.push("c".to_string());
+        list.push_front("c".to_string());
         assert_eq!(format!("{}", list), "[c, b, a]");
     }
 
     #[test]
     fn test_iter() {
         let mut list = LinkedList::new();
-        list.push("c".to_string());
-        list.push("b".to_string());
-        list.push("a".to_string());
+        list.push_front("c".to_string());
+        list.push_front("b".to_string());
+        list.push_front("a".to_string());
 
         let mut iter = list.iter();
         assert_eq!(iter.next(), Some(&"a".to_string()));
@@ -194,9 +830,9 @@ mod tests {
     #[test]
     fn test_iter_mut() {
         let mut list = LinkedList::new();
-        list.push("c".to_string());
-        list.push("b".to_string());
-        list.push("a".to_string());
+        list.push_front("c".to_string());
+        list.push_front("b".to_string());
+        list.push_front("a".to_string());
 
         for item in list.iter_mut() {
             item.push_str("!");
@@ -204,4 +840,263 @@ mod tests {
         assert_eq!(format!("{}", list), "[a!, b!, c!]");
     }
 
+    #[test]
+    fn test_drop_long_list_does_not_overflow_stack() {
+        let mut list = LinkedList::new();
+        for i in 0..100_000 {
+            list.push_back(i);
+        }
+        drop(list);
+    }
+
+    #[test]
+    fn test_cursor_move_and_current() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.current(), Some(&mut 1));
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 2));
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 3));
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 1));
+    }
+
+    #[test]
+    fn test_cursor_peek() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        assert_eq!(cursor.peek_prev(), Some(&mut 1));
+        assert_eq!(cursor.peek_next(), Some(&mut 3));
+    }
+
+    #[test]
+    fn test_cursor_insert_after_and_before() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(3);
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.insert_after(2);
+        assert_eq!(format!("{}", list), "[1, 2, 3]");
+
+        let mut cursor = list.cursor_back_mut();
+        cursor.insert_before(99);
+        assert_eq!(format!("{}", list), "[1, 2, 99, 3]");
+    }
+
+    #[test]
+    fn test_cursor_insert_at_ghost_on_empty_list() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        let mut cursor = list.cursor_front_mut();
+        cursor.insert_after(1);
+        assert_eq!(format!("{}", list), "[1]");
+    }
+
+    #[test]
+    fn test_cursor_remove_current() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!(cursor.current(), Some(&mut 3));
+        drop(cursor);
+        assert_eq!(format!("{}", list), "[1, 3]");
+    }
+
+    #[test]
+    fn test_cursor_remove_only_node() {
+        let mut list = LinkedList::new();
+        list.push_back(42);
+
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.remove_current(), Some(42));
+        assert_eq!(cursor.current(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_cursor_split_after() {
+        let mut list = LinkedList::new();
+        for i in 1..=5 {
+            list.push_back(i);
+        }
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        let tail = cursor.split_after();
+
+        assert_eq!(format!("{}", list), "[1, 2, 3]");
+        assert_eq!(format!("{}", tail), "[4, 5]");
+        assert_eq!(list.len(), 3);
+        assert_eq!(tail.len(), 2);
+    }
+
+    #[test]
+    fn test_cursor_split_before() {
+        let mut list = LinkedList::new();
+        for i in 1..=5 {
+            list.push_back(i);
+        }
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        let front = cursor.split_before();
+
+        assert_eq!(format!("{}", front), "[1, 2]");
+        assert_eq!(format!("{}", list), "[3, 4, 5]");
+        assert_eq!(front.len(), 2);
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let list: LinkedList<i32> = (1..=3).collect();
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_into_iter_double_ended() {
+        let list: LinkedList<i32> = (1..=5).collect();
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(5));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_iter_double_ended() {
+        let list: LinkedList<i32> = (1..=3).collect();
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next_back(), Some(&2));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_for_loop_over_reference_and_owned() {
+        let list: LinkedList<i32> = vec![1, 2, 3].into_iter().collect();
+
+        let mut sum = 0;
+        for item in &list {
+            sum += item;
+        }
+        assert_eq!(sum, 6);
+
+        let mut collected = Vec::new();
+        for item in list {
+            collected.push(item);
+        }
+        assert_eq!(collected, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        list.push_back(1);
+        list.extend(vec![2, 3, 4]);
+        assert_eq!(format!("{}", list), "[1, 2, 3, 4]");
+    }
+
+    #[test]
+    fn test_clone_eq_hash() {
+        use std::collections::HashSet;
+
+        let a: LinkedList<i32> = (1..=3).collect();
+        let b = a.clone();
+        assert!(a == b);
+
+        let c: LinkedList<i32> = (1..=4).collect();
+        assert!(a != c);
+
+        let mut set = HashSet::new();
+        set.insert(a);
+        assert!(set.contains(&b));
+    }
+
+    #[test]
+    fn test_append() {
+        let mut a: LinkedList<i32> = (1..=3).collect();
+        let mut b: LinkedList<i32> = (4..=6).collect();
+
+        a.append(&mut b);
+
+        assert_eq!(format!("{}", a), "[1, 2, 3, 4, 5, 6]");
+        assert_eq!(a.len(), 6);
+        assert!(b.is_empty());
+        assert_eq!(b.len(), 0);
+    }
+
+    #[test]
+    fn test_append_to_empty() {
+        let mut a: LinkedList<i32> = LinkedList::new();
+        let mut b: LinkedList<i32> = (1..=3).collect();
+
+        a.append(&mut b);
+
+        assert_eq!(format!("{}", a), "[1, 2, 3]");
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut list: LinkedList<i32> = (1..=5).collect();
+        let tail = list.split_off(2);
+
+        assert_eq!(format!("{}", list), "[1, 2]");
+        assert_eq!(format!("{}", tail), "[3, 4, 5]");
+        assert_eq!(list.len(), 2);
+        assert_eq!(tail.len(), 3);
+    }
+
+    #[test]
+    fn test_split_off_at_zero_and_len() {
+        let mut list: LinkedList<i32> = (1..=3).collect();
+        let all = list.split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(format!("{}", all), "[1, 2, 3]");
+
+        let mut list: LinkedList<i32> = (1..=3).collect();
+        let empty = list.split_off(3);
+        assert_eq!(format!("{}", list), "[1, 2, 3]");
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_contains() {
+        let list: LinkedList<i32> = (1..=3).collect();
+        assert!(list.contains(&2));
+        assert!(!list.contains(&9));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut list: LinkedList<i32> = (1..=4).collect();
+        assert_eq!(list.remove(1), Some(2));
+        assert_eq!(format!("{}", list), "[1, 3, 4]");
+        assert_eq!(list.remove(10), None);
+        assert_eq!(list.remove(2), Some(4));
+        assert_eq!(format!("{}", list), "[1, 3]");
+    }
 }
@@ -0,0 +1,137 @@
+use std::rc::Rc;
+
+struct Node<T> {
+    elem: T,
+    next: Option<Rc<Node<T>>>,
+}
+
+/// An immutable, structurally-shared singly-linked stack. `prepend` and
+/// `tail` return a *new* list that shares its tail with the list it was
+/// derived from, so making a new version is O(1) and cheap to keep around
+/// (e.g. to hand the same suffix to multiple callers).
+pub struct PersistentList<T> {
+    head: Option<Rc<Node<T>>>,
+}
+
+impl<T> PersistentList<T> {
+    pub fn new() -> Self {
+        PersistentList { head: None }
+    }
+
+    /// Returns a new list with `elem` on the front, sharing the rest of
+    /// `self` via a cloned `Rc`.
+    pub fn prepend(&self, elem: T) -> PersistentList<T> {
+        PersistentList {
+            head: Some(Rc::new(Node {
+                elem,
+                next: self.head.clone(),
+            })),
+        }
+    }
+
+    /// Returns the list starting after the front element.
+    pub fn tail(&self) -> PersistentList<T> {
+        PersistentList {
+            head: self.head.as_ref().and_then(|node| node.next.clone()),
+        }
+    }
+
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.elem)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+}
+
+// Nodes can be shared by several lists, so dropping one list must not free
+// a node that another list still points to. Walk the chain unwrapping each
+// `Rc` iteratively; as soon as one is still shared (`Rc::try_unwrap` fails)
+// the rest of the chain is left alone for its other owners to drop.
+impl<T> Drop for PersistentList<T> {
+    fn drop(&mut self) {
+        let mut head = self.head.take();
+        while let Some(node) = head {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => head = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.elem
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prepend_and_head() {
+        let list = PersistentList::new();
+        assert_eq!(list.head(), None);
+
+        let list = list.prepend(1).prepend(2).prepend(3);
+        assert_eq!(list.head(), Some(&3));
+    }
+
+    #[test]
+    fn test_tail() {
+        let list = PersistentList::new().prepend(1).prepend(2).prepend(3);
+
+        let list = list.tail();
+        assert_eq!(list.head(), Some(&2));
+
+        let list = list.tail();
+        assert_eq!(list.head(), Some(&1));
+
+        let list = list.tail();
+        assert_eq!(list.head(), None);
+
+        assert_eq!(list.tail().head(), None);
+    }
+
+    #[test]
+    fn test_iter() {
+        let list = PersistentList::new().prepend(1).prepend(2).prepend(3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_structural_sharing() {
+        let a = PersistentList::new().prepend(1).prepend(2).prepend(3);
+        let b = a.tail().prepend(99);
+
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![&3, &2, &1]);
+        assert_eq!(b.iter().collect::<Vec<_>>(), vec![&99, &2, &1]);
+    }
+
+    #[test]
+    fn test_drop_long_list_does_not_overflow_stack() {
+        let mut list = PersistentList::new();
+        for i in 0..100_000 {
+            list = list.prepend(i);
+        }
+        drop(list);
+    }
+}